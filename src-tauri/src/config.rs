@@ -0,0 +1,89 @@
+//! Persistent user preferences, stored as a single JSON blob in the
+//! `config` table (see `migrations::MIGRATIONS`, V2).
+
+use crate::errors::AppError;
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqlitePool, Row};
+
+const CONFIG_NAME: &str = "main";
+
+fn default_cola_assumption() -> f64 {
+    0.02
+}
+
+fn default_tax_state() -> String {
+    "".to_string()
+}
+
+fn default_display_currency() -> String {
+    "USD".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfig {
+    #[serde(default = "default_cola_assumption")]
+    pub default_cola_assumption: f64,
+    #[serde(default = "default_tax_state")]
+    pub preferred_tax_state: String,
+    #[serde(default = "default_display_currency")]
+    pub display_currency: String,
+    #[serde(default)]
+    pub last_opened_scenario_id: Option<String>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            default_cola_assumption: default_cola_assumption(),
+            preferred_tax_state: default_tax_state(),
+            display_currency: default_display_currency(),
+            last_opened_scenario_id: None,
+        }
+    }
+}
+
+impl AppConfig {
+    pub async fn load(pool: &SqlitePool) -> Result<Self, AppError> {
+        let row = sqlx::query("SELECT data FROM config WHERE name = ?")
+            .bind(CONFIG_NAME)
+            .fetch_optional(pool)
+            .await?;
+
+        let Some(row) = row else {
+            return Ok(Self::default());
+        };
+
+        let data: String = row.get("data");
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    pub async fn save(&self, pool: &SqlitePool) -> Result<(), AppError> {
+        let data = serde_json::to_string(self)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO config (name, data) VALUES (?, ?)
+            ON CONFLICT(name) DO UPDATE SET data = excluded.data
+            "#,
+        )
+        .bind(CONFIG_NAME)
+        .bind(data)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[tauri::command]
+pub async fn get_config(pool: tauri::State<'_, SqlitePool>) -> Result<AppConfig, AppError> {
+    AppConfig::load(&pool).await
+}
+
+#[tauri::command]
+pub async fn save_config(
+    pool: tauri::State<'_, SqlitePool>,
+    config: AppConfig,
+) -> Result<(), AppError> {
+    config.save(&pool).await
+}
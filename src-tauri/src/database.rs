@@ -1,6 +1,11 @@
+use crate::errors::AppError;
+use crate::migrations::{self, Migration};
 use serde::{Deserialize, Serialize};
-use sqlx::{sqlite::SqlitePool, Row};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use sqlx::Row;
 use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
 use tauri::AppHandle;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -12,43 +17,116 @@ pub struct SavedScenario {
     pub updated_at: String,
 }
 
-pub async fn init_database(app_handle: &AppHandle) -> Result<SqlitePool, Box<dyn std::error::Error>> {
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScenarioRevision {
+    pub revision_id: i64,
+    pub scenario_id: String,
+    pub data: String, // JSON serialized scenario data, as of this revision
+    pub created_at: String,
+}
+
+/// Picks a dedicated `./ferex.dev.db` in debug builds so local development
+/// never touches the real user profile, and the platform app-data dir
+/// otherwise.
+fn get_or_create_db_path(app_handle: &AppHandle) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    if cfg!(debug_assertions) {
+        return Ok(PathBuf::from("./ferex.dev.db"));
+    }
+
     let app_dir = app_handle
         .path_resolver()
         .app_local_data_dir()
         .expect("failed to resolve app data directory");
-    
-    // Create the directory if it doesn't exist
+
     fs::create_dir_all(&app_dir)?;
-    
-    let database_path = app_dir.join("ferex.db");
-    let database_url = format!("sqlite:{}", database_path.display());
-    
-    let pool = SqlitePool::connect(&database_url).await?;
-    
-    // Create scenarios table
+
+    Ok(app_dir.join("ferex.db"))
+}
+
+pub async fn init_database(app_handle: &AppHandle) -> Result<SqlitePool, Box<dyn std::error::Error>> {
+    let connect_options = match std::env::var("DATABASE_URL") {
+        Ok(database_url) => SqliteConnectOptions::from_str(&database_url)?,
+        Err(_) => {
+            let database_path = get_or_create_db_path(app_handle)?;
+            SqliteConnectOptions::new().filename(database_path)
+        }
+    }
+    .create_if_missing(true);
+
+    let pool = SqlitePoolOptions::new()
+        .connect_with(connect_options)
+        .await?;
+
+    apply_migrations(&pool).await?;
+
+    Ok(pool)
+}
+
+/// Applies every migration in `migrations::MIGRATIONS` whose version exceeds
+/// the stored `schema_version`, each inside its own transaction.
+async fn apply_migrations(pool: &SqlitePool) -> Result<(), sqlx::Error> {
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS scenarios (
-            id TEXT PRIMARY KEY,
-            name TEXT NOT NULL,
-            data TEXT NOT NULL,
-            created_at TEXT NOT NULL,
-            updated_at TEXT NOT NULL
+        CREATE TABLE IF NOT EXISTS schema_version (
+            version INTEGER NOT NULL
         )
         "#,
     )
-    .execute(&pool)
+    .execute(pool)
     .await?;
-    
-    Ok(pool)
+
+    sqlx::query(
+        "INSERT INTO schema_version (version) SELECT 0 WHERE NOT EXISTS (SELECT 1 FROM schema_version)",
+    )
+    .execute(pool)
+    .await?;
+
+    let mut current = current_schema_version(pool).await?;
+
+    for migration @ Migration { version, sql, .. } in migrations::MIGRATIONS {
+        if *version <= current {
+            continue;
+        }
+
+        let mut tx = pool.begin().await?;
+        sqlx::query(sql).execute(&mut *tx).await?;
+        sqlx::query("UPDATE schema_version SET version = ?")
+            .bind(version)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        current = *version;
+        println!("Applied migration {}: {}", version, migration.name);
+    }
+
+    Ok(())
+}
+
+/// Reads the currently applied schema version, so callers (and the frontend,
+/// via `get_schema_version`) can tell which migrations have landed.
+pub async fn current_schema_version(pool: &SqlitePool) -> Result<i64, sqlx::Error> {
+    let row = sqlx::query("SELECT version FROM schema_version LIMIT 1")
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row.map(|r| r.get::<i64, _>("version")).unwrap_or(0))
 }
 
+#[tauri::command]
+pub async fn get_schema_version(pool: tauri::State<'_, SqlitePool>) -> Result<i64, AppError> {
+    Ok(current_schema_version(&pool).await?)
+}
+
+/// Upserts the scenario head and appends an immutable revision, so prior
+/// versions survive even though the head row is overwritten on every save.
 #[tauri::command]
 pub async fn save_scenario(
     pool: tauri::State<'_, SqlitePool>,
     scenario: SavedScenario,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
     sqlx::query(
         r#"
         INSERT OR REPLACE INTO scenarios (id, name, data, created_at, updated_at)
@@ -60,22 +138,97 @@ pub async fn save_scenario(
     .bind(&scenario.data)
     .bind(&scenario.created_at)
     .bind(&scenario.updated_at)
-    .execute(&*pool)
-    .await
-    .map_err(|e| e.to_string())?;
-    
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO scenario_revisions (scenario_id, data, created_at)
+        VALUES (?, ?, ?)
+        "#,
+    )
+    .bind(&scenario.id)
+    .bind(&scenario.data)
+    .bind(&scenario.updated_at)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn load_scenario_history(
+    pool: tauri::State<'_, SqlitePool>,
+    id: String,
+) -> Result<Vec<ScenarioRevision>, AppError> {
+    let rows = sqlx::query(
+        "SELECT revision_id, scenario_id, data, created_at FROM scenario_revisions \
+         WHERE scenario_id = ? ORDER BY revision_id ASC",
+    )
+    .bind(&id)
+    .fetch_all(&*pool)
+    .await?;
+
+    let revisions = rows
+        .into_iter()
+        .map(|row| ScenarioRevision {
+            revision_id: row.get("revision_id"),
+            scenario_id: row.get("scenario_id"),
+            data: row.get("data"),
+            created_at: row.get("created_at"),
+        })
+        .collect();
+
+    Ok(revisions)
+}
+
+/// Promotes an old revision back to head, leaving the revision history
+/// itself untouched so the restore can be undone by restoring again.
+#[tauri::command]
+pub async fn restore_revision(
+    pool: tauri::State<'_, SqlitePool>,
+    id: String,
+    revision_id: i64,
+) -> Result<(), AppError> {
+    let row = sqlx::query(
+        "SELECT data, created_at FROM scenario_revisions WHERE revision_id = ? AND scenario_id = ?",
+    )
+    .bind(revision_id)
+    .bind(&id)
+    .fetch_optional(&*pool)
+    .await?;
+
+    let Some(row) = row else {
+        return Err(AppError::NotFound);
+    };
+
+    let data: String = row.get("data");
+    let created_at: String = row.get("created_at");
+
+    let result = sqlx::query("UPDATE scenarios SET data = ?, updated_at = ? WHERE id = ?")
+        .bind(&data)
+        .bind(&created_at)
+        .bind(&id)
+        .execute(&*pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound);
+    }
+
     Ok(())
 }
 
 #[tauri::command]
 pub async fn load_scenarios(
     pool: tauri::State<'_, SqlitePool>,
-) -> Result<Vec<SavedScenario>, String> {
+) -> Result<Vec<SavedScenario>, AppError> {
     let rows = sqlx::query("SELECT * FROM scenarios ORDER BY updated_at DESC")
         .fetch_all(&*pool)
-        .await
-        .map_err(|e| e.to_string())?;
-    
+        .await?;
+
     let scenarios = rows
         .into_iter()
         .map(|row| SavedScenario {
@@ -86,7 +239,7 @@ pub async fn load_scenarios(
             updated_at: row.get("updated_at"),
         })
         .collect();
-    
+
     Ok(scenarios)
 }
 
@@ -94,12 +247,15 @@ pub async fn load_scenarios(
 pub async fn delete_scenario(
     pool: tauri::State<'_, SqlitePool>,
     id: String,
-) -> Result<(), String> {
-    sqlx::query("DELETE FROM scenarios WHERE id = ?")
+) -> Result<(), AppError> {
+    let result = sqlx::query("DELETE FROM scenarios WHERE id = ?")
         .bind(&id)
         .execute(&*pool)
-        .await
-        .map_err(|e| e.to_string())?;
-    
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound);
+    }
+
     Ok(())
 }
\ No newline at end of file
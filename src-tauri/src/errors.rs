@@ -0,0 +1,39 @@
+//! Structured error type for Tauri commands.
+//!
+//! `AppError` serializes to a tagged `{ kind, message }` object so the
+//! frontend can branch on `kind` instead of string-matching error text.
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("not found")]
+    NotFound,
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("validation error: {0}")]
+    Validation(String),
+}
+
+impl Serialize for AppError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let kind = match self {
+            AppError::Database(_) => "database",
+            AppError::NotFound => "not_found",
+            AppError::Serialization(_) => "serialization",
+            AppError::Validation(_) => "validation",
+        };
+
+        let mut state = serializer.serialize_struct("AppError", 2)?;
+        state.serialize_field("kind", kind)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
@@ -1,9 +1,19 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod config;
 mod database;
+mod errors;
+mod migrations;
+mod pension;
 
-use database::{init_database, save_scenario, load_scenarios, delete_scenario};
+use config::{get_config, save_config};
+use database::{
+    delete_scenario, get_schema_version, init_database, load_scenario_history, load_scenarios,
+    restore_revision, save_scenario,
+};
+use errors::AppError;
+use pension::{PensionBreakdown, PensionInput};
 use tauri::Manager;
 
 // Learn more about Tauri commands at https://tauri.app/v1/guides/features/command
@@ -13,18 +23,8 @@ fn greet(name: &str) -> String {
 }
 
 #[tauri::command]
-async fn calculate_fers_pension(
-    service_years: f64,
-    high_three: f64,
-    age_at_retirement: u32,
-) -> Result<f64, String> {
-    // FERS pension calculation
-    let multiplier = if age_at_retirement >= 62 && service_years >= 20.0 {
-        0.011 // 1.1% for age 62+ with 20+ years
-    } else {
-        0.01 // 1.0% standard multiplier
-    };
-    Ok(high_three * service_years * multiplier)
+async fn calculate_fers_pension(input: PensionInput) -> Result<PensionBreakdown, AppError> {
+    pension::calculate(&input)
 }
 
 fn main() {
@@ -45,11 +45,16 @@ fn main() {
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
-            greet, 
-            calculate_fers_pension, 
-            save_scenario, 
-            load_scenarios, 
-            delete_scenario
+            greet,
+            calculate_fers_pension,
+            save_scenario,
+            load_scenarios,
+            delete_scenario,
+            load_scenario_history,
+            restore_revision,
+            get_schema_version,
+            get_config,
+            save_config
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,48 @@
+//! Ordered schema migrations applied by `database::init_database`.
+//!
+//! Each migration is a numbered, immutable SQL step. Once a migration has
+//! shipped, its `sql` must never change — add a new migration instead.
+
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "V1__initial",
+        sql: r#"
+        CREATE TABLE IF NOT EXISTS scenarios (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            data TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+    },
+    Migration {
+        version: 2,
+        name: "V2__add_config_table",
+        sql: r#"
+        CREATE TABLE IF NOT EXISTS config (
+            name TEXT UNIQUE NOT NULL,
+            data TEXT NOT NULL
+        )
+        "#,
+    },
+    Migration {
+        version: 3,
+        name: "V3__add_scenario_revisions",
+        sql: r#"
+        CREATE TABLE IF NOT EXISTS scenario_revisions (
+            revision_id INTEGER PRIMARY KEY AUTOINCREMENT,
+            scenario_id TEXT NOT NULL REFERENCES scenarios(id),
+            data TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        )
+        "#,
+    },
+];
@@ -0,0 +1,140 @@
+//! FERS annuity engine.
+//!
+//! Computes a line-item `PensionBreakdown` from a `PensionInput`, so the UI
+//! can explain each component instead of showing a single number.
+
+use crate::errors::AppError;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+const FULL_RETIREMENT_AGE: f64 = 62.0;
+const MRA_REDUCTION_PER_YEAR: f64 = 0.05;
+const SURVIVOR_FULL_REDUCTION: f64 = 0.10;
+const SURVIVOR_PARTIAL_REDUCTION: f64 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetirementType {
+    Immediate,
+    EarlyMraPlus10,
+    Deferred,
+    Disability,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SurvivorBenefit {
+    None,
+    Partial,
+    Full,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PensionInput {
+    pub date_of_birth: NaiveDate,
+    pub retirement_date: NaiveDate,
+    pub creditable_service_years: u32,
+    pub creditable_service_months: u32,
+    pub high_three: f64,
+    pub retirement_type: RetirementType,
+    /// Estimated Social Security benefit, used for the Special Retirement
+    /// Supplement. `None` when the user hasn't provided an estimate yet.
+    pub social_security_estimate: Option<f64>,
+    pub survivor_benefit: SurvivorBenefit,
+}
+
+impl PensionInput {
+    fn age_at_retirement_years(&self) -> f64 {
+        let days = (self.retirement_date - self.date_of_birth).num_days();
+        days as f64 / 365.25
+    }
+
+    fn total_service_years(&self) -> f64 {
+        self.creditable_service_years as f64 + (self.creditable_service_months as f64 / 12.0)
+    }
+}
+
+/// Line-item breakdown of a FERS annuity, with `input` carried alongside the
+/// result so saved scenarios remain reproducible.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PensionBreakdown {
+    pub input: PensionInput,
+    pub age_at_retirement_years: f64,
+    pub total_service_years: f64,
+    /// FERS basic annuity before any reductions, using the 1.0%/1.1% tier.
+    pub basic_annuity: f64,
+    /// MRA+10 age reduction subtracted from the basic annuity (5% per year
+    /// under 62, prorated monthly).
+    pub age_reduction: f64,
+    /// Estimated Special Retirement Supplement, paid only until age 62.
+    pub special_retirement_supplement: f64,
+    /// Survivor-benefit election reduction subtracted from the annuity.
+    pub survivor_reduction: f64,
+    pub total_annual_annuity: f64,
+}
+
+pub fn calculate(input: &PensionInput) -> Result<PensionBreakdown, AppError> {
+    if input.high_three < 0.0 {
+        return Err(AppError::Validation(
+            "high_three must be non-negative".into(),
+        ));
+    }
+    if input.retirement_date < input.date_of_birth {
+        return Err(AppError::Validation(
+            "retirement_date must be after date_of_birth".into(),
+        ));
+    }
+
+    let age_at_retirement_years = input.age_at_retirement_years();
+    let total_service_years = input.total_service_years();
+
+    // 1.1% for age 62+ with 20+ years, 1.0% otherwise.
+    let multiplier = if age_at_retirement_years >= FULL_RETIREMENT_AGE && total_service_years >= 20.0
+    {
+        0.011
+    } else {
+        0.01
+    };
+
+    let basic_annuity = input.high_three * total_service_years * multiplier;
+
+    let age_reduction = if input.retirement_type == RetirementType::EarlyMraPlus10
+        && age_at_retirement_years < FULL_RETIREMENT_AGE
+    {
+        let years_under_62 = FULL_RETIREMENT_AGE - age_at_retirement_years;
+        basic_annuity * MRA_REDUCTION_PER_YEAR * years_under_62
+    } else {
+        0.0
+    };
+
+    let special_retirement_supplement = if age_at_retirement_years < FULL_RETIREMENT_AGE {
+        input
+            .social_security_estimate
+            .map(|estimate| estimate * total_service_years / 40.0)
+            .unwrap_or(0.0)
+    } else {
+        0.0
+    };
+
+    let annuity_after_age_reduction = basic_annuity - age_reduction;
+
+    let survivor_reduction = match input.survivor_benefit {
+        SurvivorBenefit::Full => annuity_after_age_reduction * SURVIVOR_FULL_REDUCTION,
+        SurvivorBenefit::Partial => annuity_after_age_reduction * SURVIVOR_PARTIAL_REDUCTION,
+        SurvivorBenefit::None => 0.0,
+    };
+
+    let total_annual_annuity =
+        annuity_after_age_reduction - survivor_reduction + special_retirement_supplement;
+
+    Ok(PensionBreakdown {
+        input: input.clone(),
+        age_at_retirement_years,
+        total_service_years,
+        basic_annuity,
+        age_reduction,
+        special_retirement_supplement,
+        survivor_reduction,
+        total_annual_annuity,
+    })
+}